@@ -0,0 +1,330 @@
+//! Named type coercions for turning string-shaped Ruby [`Value`]s into
+//! typed ones.
+//!
+//! [`Conversion`] mirrors the small set of coercions used by log and data
+//! pipeline tooling so embedders can turn a `String` into an `Integer`,
+//! `Float`, `Boolean`, or `Time` declaratively instead of hand-writing
+//! `funcall("to_i")` chains.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+use crate::convert::Convert;
+use crate::value::{Value, ValueLike};
+use crate::{Artichoke, ArtichokeError};
+
+/// A named coercion from a Ruby `String` to another Ruby type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Conversion {
+    /// Leave the value as a `String`.
+    Bytes,
+    /// Parse the value as an `Integer`.
+    Integer,
+    /// Parse the value as a `Float`.
+    Float,
+    /// Parse the value as `true`/`false`.
+    Boolean,
+    /// Parse the value as a `Time` using RFC 3339.
+    Timestamp,
+    /// Parse the value as a `Time` using the given `chrono`-style format
+    /// string.
+    TimestampFmt(String),
+}
+
+impl FromStr for Conversion {
+    type Err = UnknownConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "string" | "bytes" | "asis" => Ok(Self::Bytes),
+            "int" | "integer" => Ok(Self::Integer),
+            "float" => Ok(Self::Float),
+            "bool" | "boolean" => Ok(Self::Boolean),
+            "timestamp" => Ok(Self::Timestamp),
+            _ => {
+                if let Some(fmt) = s.strip_prefix("timestamp|") {
+                    Ok(Self::TimestampFmt(fmt.to_owned()))
+                } else {
+                    Err(UnknownConversionError(s.to_owned()))
+                }
+            }
+        }
+    }
+}
+
+/// Error returned when a [`Conversion`] name does not match a known
+/// coercion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownConversionError(String);
+
+impl fmt::Display for UnknownConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown conversion: {}", self.0)
+    }
+}
+
+impl error::Error for UnknownConversionError {}
+
+/// Coerce a Ruby `String` [`Value`] into the Ruby type named by `conv`.
+///
+/// # Errors
+///
+/// If `value` is not a `String`, or if the string cannot be parsed as the
+/// target type, an [`ArtichokeError`] is returned.
+pub fn coerce(interp: &Artichoke, value: &Value, conv: &Conversion) -> Result<Value, ArtichokeError> {
+    let string = value.clone().try_into::<String>()?;
+    match conv {
+        Conversion::Bytes => Ok(interp.convert(string)),
+        Conversion::Integer => parse_integer(&string).map(|int| interp.convert(int)),
+        Conversion::Float => parse_float(&string).map(|float| interp.convert(float)),
+        Conversion::Boolean => parse_bool(&string).map(|truthy| interp.convert(truthy)),
+        Conversion::Timestamp => {
+            let parsed = DateTime::parse_from_rfc3339(string.trim()).map_err(|err| {
+                ArtichokeError::Conversion(format!("invalid RFC 3339 timestamp: {}", err))
+            })?;
+            time_at(interp, parsed.timestamp())
+        }
+        Conversion::TimestampFmt(fmt) => {
+            let timestamp = parse_timestamp_fmt(&string, fmt)?;
+            time_at(interp, timestamp)
+        }
+    }
+}
+
+/// Parse a `String` as an `Integer` after trimming surrounding whitespace,
+/// rejecting any trimmed input `str::parse` itself would reject (unlike
+/// Ruby's permissive `String#to_i`, which coerces unparsable input to `0`).
+fn parse_integer(string: &str) -> Result<i64, ArtichokeError> {
+    string
+        .trim()
+        .parse::<i64>()
+        .map_err(|err| ArtichokeError::Conversion(format!("invalid integer {:?}: {}", string, err)))
+}
+
+/// Parse a `String` as a `Float` after trimming surrounding whitespace,
+/// rejecting any trimmed input `str::parse` itself would reject (unlike
+/// Ruby's permissive `String#to_f`, which coerces unparsable input to
+/// `0.0`).
+fn parse_float(string: &str) -> Result<f64, ArtichokeError> {
+    string
+        .trim()
+        .parse::<f64>()
+        .map_err(|err| ArtichokeError::Conversion(format!("invalid float {:?}: {}", string, err)))
+}
+
+/// Parse a `String` as one of `true/false/1/0/yes/no`, case-insensitively.
+/// Any other input is rejected rather than silently treated as `false`.
+fn parse_bool(string: &str) -> Result<bool, ArtichokeError> {
+    match string.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" => Ok(true),
+        "false" | "0" | "no" => Ok(false),
+        _ => Err(ArtichokeError::Conversion(format!(
+            "invalid boolean {:?}: expected one of true/false/1/0/yes/no",
+            string
+        ))),
+    }
+}
+
+/// Parse a `String` as a Unix timestamp using the given `chrono`-style
+/// format string.
+///
+/// Tries a full date-time parse first; if `fmt` has no time component (a
+/// bare date, e.g. `"%Y-%m-%d"`), `NaiveDateTime::parse_from_str` always
+/// errors since it requires both a date and a time, so this falls back to
+/// parsing a bare `NaiveDate` and taking midnight on that date.
+fn parse_timestamp_fmt(string: &str, fmt: &str) -> Result<i64, ArtichokeError> {
+    let trimmed = string.trim();
+    if let Ok(parsed) = NaiveDateTime::parse_from_str(trimmed, fmt) {
+        return Ok(parsed.timestamp());
+    }
+    NaiveDate::parse_from_str(trimmed, fmt)
+        .map(|date| date.and_hms(0, 0, 0).timestamp())
+        .map_err(|err| {
+            ArtichokeError::Conversion(format!(
+                "timestamp {:?} does not match format {:?}: {}",
+                string, fmt, err
+            ))
+        })
+}
+
+fn time_at(interp: &Artichoke, timestamp: i64) -> Result<Value, ArtichokeError> {
+    let time_class = interp.eval("Time")?;
+    time_class.funcall::<Value>("at", &[interp.convert(timestamp)], None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bool, parse_float, parse_integer, parse_timestamp_fmt, Conversion};
+    use crate::convert::Convert;
+    use crate::value::{Value, ValueLike};
+    use crate::ArtichokeError;
+
+    fn class_name(value: &Value) -> String {
+        value
+            .funcall::<Value>("class", &[], None)
+            .and_then(|class| class.funcall::<String>("name", &[], None))
+            .expect("class name")
+    }
+
+    #[test]
+    fn coerce_bytes_returns_the_string_unchanged() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("interstate");
+        let coerced = value.coerce(&Conversion::Bytes).expect("coerce");
+        assert_eq!(class_name(&coerced), "String");
+        assert_eq!(coerced.to_s(), "interstate");
+    }
+
+    #[test]
+    fn coerce_integer_produces_a_ruby_integer() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("42");
+        let coerced = value.coerce(&Conversion::Integer).expect("coerce");
+        assert_eq!(class_name(&coerced), "Fixnum");
+        assert_eq!(coerced.to_s(), "42");
+    }
+
+    #[test]
+    fn coerce_float_produces_a_ruby_float() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("1.5");
+        let coerced = value.coerce(&Conversion::Float).expect("coerce");
+        assert_eq!(class_name(&coerced), "Float");
+        assert_eq!(coerced.to_s(), "1.5");
+    }
+
+    #[test]
+    fn coerce_boolean_produces_true_and_false() {
+        let interp = crate::interpreter().expect("init");
+
+        let truthy = interp.convert("yes").coerce(&Conversion::Boolean).expect("coerce");
+        assert_eq!(class_name(&truthy), "TrueClass");
+        assert_eq!(truthy.to_s(), "true");
+
+        let falsy = interp.convert("no").coerce(&Conversion::Boolean).expect("coerce");
+        assert_eq!(class_name(&falsy), "FalseClass");
+        assert_eq!(falsy.to_s(), "false");
+    }
+
+    #[test]
+    fn coerce_timestamp_parses_rfc3339_into_a_ruby_time() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("2020-01-02T03:04:05Z");
+        let coerced = value.coerce(&Conversion::Timestamp).expect("coerce");
+        assert_eq!(class_name(&coerced), "Time");
+        let epoch = coerced.funcall::<i64>("to_i", &[], None).expect("to_i");
+        assert_eq!(epoch, 1577934245);
+    }
+
+    #[test]
+    fn coerce_timestamp_fmt_parses_a_custom_format_into_a_ruby_time() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("2020-01-02");
+        let coerced = value
+            .coerce(&Conversion::TimestampFmt("%Y-%m-%d".to_owned()))
+            .expect("coerce");
+        assert_eq!(class_name(&coerced), "Time");
+        let epoch = coerced.funcall::<i64>("to_i", &[], None).expect("to_i");
+        assert_eq!(epoch, 1577923200);
+    }
+
+    #[test]
+    fn coerce_on_non_string_value_errors() {
+        let interp = crate::interpreter().expect("init");
+
+        let value: Value = interp.convert(42);
+        assert!(value.coerce(&Conversion::Bytes).is_err());
+    }
+
+    #[test]
+    fn coerce_unparsable_integer_returns_conversion_error() {
+        let interp = crate::interpreter().expect("init");
+
+        let value = interp.convert("not a number");
+        match value.coerce(&Conversion::Integer) {
+            Err(ArtichokeError::Conversion(_)) => {}
+            other => panic!("expected ArtichokeError::Conversion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn from_str_maps_known_names() {
+        assert_eq!("string".parse(), Ok(Conversion::Bytes));
+        assert_eq!("bytes".parse(), Ok(Conversion::Bytes));
+        assert_eq!("asis".parse(), Ok(Conversion::Bytes));
+        assert_eq!("int".parse(), Ok(Conversion::Integer));
+        assert_eq!("integer".parse(), Ok(Conversion::Integer));
+        assert_eq!("float".parse(), Ok(Conversion::Float));
+        assert_eq!("bool".parse(), Ok(Conversion::Boolean));
+        assert_eq!("boolean".parse(), Ok(Conversion::Boolean));
+        assert_eq!("timestamp".parse(), Ok(Conversion::Timestamp));
+        assert_eq!(
+            "timestamp|%Y-%m-%d".parse(),
+            Ok(Conversion::TimestampFmt("%Y-%m-%d".to_owned()))
+        );
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_name() {
+        assert!("garbage".parse::<Conversion>().is_err());
+    }
+
+    #[test]
+    fn parse_integer_accepts_valid_and_rejects_garbage() {
+        assert_eq!(parse_integer("123"), Ok(123));
+        assert_eq!(parse_integer(" -45 "), Ok(-45));
+        assert!(parse_integer("12x").is_err());
+        assert!(parse_integer("abc").is_err());
+        assert!(parse_integer("").is_err());
+    }
+
+    #[test]
+    fn parse_float_accepts_valid_and_rejects_garbage() {
+        assert_eq!(parse_float("1.5"), Ok(1.5));
+        assert!(parse_float("1.5x").is_err());
+        assert!(parse_float("abc").is_err());
+    }
+
+    #[test]
+    fn parse_bool_accepts_enumerated_values_case_insensitively() {
+        assert_eq!(parse_bool("true"), Ok(true));
+        assert_eq!(parse_bool("TRUE"), Ok(true));
+        assert_eq!(parse_bool("1"), Ok(true));
+        assert_eq!(parse_bool("yes"), Ok(true));
+        assert_eq!(parse_bool("false"), Ok(false));
+        assert_eq!(parse_bool("0"), Ok(false));
+        assert_eq!(parse_bool("no"), Ok(false));
+    }
+
+    #[test]
+    fn parse_bool_rejects_unrecognized_input() {
+        assert!(parse_bool("maybe").is_err());
+        assert!(parse_bool("").is_err());
+        assert!(parse_bool("yes please").is_err());
+    }
+
+    #[test]
+    fn parse_timestamp_fmt_accepts_date_time_format() {
+        let timestamp = parse_timestamp_fmt("2020-01-02 03:04:05", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(timestamp, 1577934245);
+    }
+
+    #[test]
+    fn parse_timestamp_fmt_falls_back_to_midnight_for_date_only_format() {
+        let timestamp = parse_timestamp_fmt("2020-01-02", "%Y-%m-%d").unwrap();
+        assert_eq!(timestamp, 1577923200);
+    }
+
+    #[test]
+    fn parse_timestamp_fmt_rejects_mismatched_input() {
+        assert!(parse_timestamp_fmt("not a date", "%Y-%m-%d").is_err());
+    }
+}
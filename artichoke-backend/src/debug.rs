@@ -0,0 +1,347 @@
+//! Debugging utilities for visualizing the object graph tracked by the
+//! mruby garbage collector's arena.
+//!
+//! Pair [`Artichoke::dump_object_graph_dot`] with
+//! [`ArenaIndex::restore`](crate::gc::ArenaIndex::restore) and
+//! [`full_gc`](crate::gc::MrbGarbageCollection::full_gc) to get a concrete,
+//! renderable view of what the GC arena considers live after an arena
+//! restore.
+//!
+//! This dumps the arena only, not the full mruby root set (VM stack,
+//! globals table, symbol table); values reachable only through those other
+//! roots will not appear in the output.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::ffi::c_void;
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::slice;
+
+use crate::convert::Convert;
+use crate::sys;
+use crate::types::Ruby;
+use crate::value::{Value, ValueLike};
+use crate::Artichoke;
+
+/// Selects whether a dump is emitted as a directed or undirected GraphViz
+/// graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A directed graph, emitted with the `digraph` keyword and `->` edges.
+    Digraph,
+    /// An undirected graph, emitted with the `graph` keyword and `--` edges.
+    Graph,
+}
+
+impl Kind {
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::Digraph => "digraph",
+            Self::Graph => "graph",
+        }
+    }
+
+    fn edge_op(self) -> &'static str {
+        match self {
+            Self::Digraph => "->",
+            Self::Graph => "--",
+        }
+    }
+}
+
+/// Incrementally builds a GraphViz DOT document of a [`Value`] graph.
+struct DotWriter {
+    kind: Kind,
+    buf: String,
+    visited: HashSet<usize>,
+}
+
+impl DotWriter {
+    fn new(kind: Kind) -> Self {
+        let mut buf = String::new();
+        let _ = writeln!(buf, "{} object_graph {{", kind.keyword());
+        Self {
+            kind,
+            buf,
+            visited: HashSet::new(),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.buf.push_str("}\n");
+        self.buf
+    }
+
+    /// Compute a stable id for `value` to key nodes and edges on.
+    ///
+    /// Heap objects are identified by their `RBasic*`. Immediates (Fixnum,
+    /// Float, Bool, Nil, Symbol) have no `RBasic*` -- the raw `mrb_value`
+    /// bits are not a pointer and can collide with a real heap address --
+    /// so they are identified by a hash of their representation instead,
+    /// tagged so the id space never overlaps with a real pointer.
+    fn id(value: &Value) -> usize {
+        match value.ruby_type() {
+            Ruby::Fixnum | Ruby::Float | Ruby::Bool | Ruby::Nil | Ruby::Symbol => {
+                immediate_id(value)
+            }
+            _ => unsafe { sys::mrb_sys_basic_ptr(value.inner()) as usize },
+        }
+    }
+
+    /// Write a node for `value` and return whether it had not already been
+    /// visited. Dead and unreachable values are marked with a placeholder
+    /// label derived only from `id`/`ruby_type` -- never `to_s_debug`,
+    /// which would `funcall("inspect")` on a value the GC may have already
+    /// swept.
+    fn node(&mut self, value: &Value) -> bool {
+        let id = Self::id(value);
+        if !self.visited.insert(id) {
+            return false;
+        }
+        if value.is_dead() {
+            let _ = writeln!(
+                self.buf,
+                "  \"{id}\" [label=\"<dead {ty}>\", color=\"gray\"];",
+                id = id,
+                ty = value.ruby_type().class_name(),
+            );
+            return true;
+        }
+        if value.is_unreachable() {
+            let _ = writeln!(
+                self.buf,
+                "  \"{id}\" [label=\"<unreachable>\", color=\"red\"];",
+                id = id,
+            );
+            return true;
+        }
+        let color = color_for_ruby_type(value.ruby_type());
+        let _ = writeln!(
+            self.buf,
+            "  \"{id}\" [label=\"{label}\", color=\"{color}\"];",
+            id = id,
+            label = escape(&value.to_s_debug()),
+            color = color,
+        );
+        true
+    }
+
+    fn edge(&mut self, from: &Value, to: &Value, label: Option<&str>) {
+        let op = self.kind.edge_op();
+        let from = Self::id(from);
+        let to = Self::id(to);
+        match label {
+            Some(label) => {
+                let _ = writeln!(
+                    self.buf,
+                    "  \"{}\" {} \"{}\" [label=\"{}\"];",
+                    from,
+                    op,
+                    to,
+                    escape(label)
+                );
+            }
+            None => {
+                let _ = writeln!(self.buf, "  \"{}\" {} \"{}\";", from, op, to);
+            }
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Synthesize a stable id for an immediate value (Fixnum, Float, Bool, Nil,
+/// Symbol) that cannot collide with a heap `RBasic*`.
+///
+/// Immediates are never shared nodes across distinct occurrences in the
+/// graph by identity the way heap objects are; this only needs to agree
+/// with itself for the *same* `mrb_value` bit pattern so that the `node()`
+/// and `edge()` calls for one occurrence point at the same id.
+fn immediate_id(value: &Value) -> usize {
+    let mut hasher = DefaultHasher::new();
+    let raw = value.inner();
+    let bytes = unsafe {
+        slice::from_raw_parts(
+            (&raw as *const sys::mrb_value).cast::<u8>(),
+            mem::size_of::<sys::mrb_value>(),
+        )
+    };
+    bytes.hash(&mut hasher);
+    immediate_type_tag(value.ruby_type()).hash(&mut hasher);
+    // Reserve the top bit for immediates so their ids never land in the
+    // (far smaller, even-aligned) address space a real heap pointer
+    // occupies.
+    (hasher.finish() as usize) | (1 << (usize::BITS - 1))
+}
+
+fn immediate_type_tag(ruby_type: Ruby) -> u8 {
+    match ruby_type {
+        Ruby::Fixnum => 1,
+        Ruby::Float => 2,
+        Ruby::Bool => 3,
+        Ruby::Nil => 4,
+        Ruby::Symbol => 5,
+        _ => 0,
+    }
+}
+
+fn color_for_ruby_type(ruby_type: Ruby) -> &'static str {
+    match ruby_type {
+        Ruby::Array => "cornflowerblue",
+        Ruby::Hash => "goldenrod",
+        Ruby::String => "forestgreen",
+        Ruby::Symbol => "darkorchid",
+        Ruby::Data => "firebrick",
+        Ruby::Unreachable => "red",
+        _ => "black",
+    }
+}
+
+/// Recursively walk `value`'s container membership -- array elements, hash
+/// key/value pairs, and instance variables -- writing nodes and edges into
+/// `writer`. Dead and unreachable values are written as leaf nodes; their
+/// contents are not walked since they are no longer safe to inspect.
+fn walk(interp: &Artichoke, writer: &mut DotWriter, value: &Value) {
+    if !writer.node(value) {
+        return;
+    }
+    if value.is_dead() || value.is_unreachable() {
+        return;
+    }
+    match value.ruby_type() {
+        Ruby::Array => {
+            if let Ok(elements) = value.funcall::<Vec<Value>>("to_a", &[], None) {
+                for element in &elements {
+                    writer.edge(value, element, None);
+                    walk(interp, writer, element);
+                }
+            }
+        }
+        Ruby::Hash => {
+            if let Ok(pairs) = value.funcall::<Vec<(Value, Value)>>("to_a", &[], None) {
+                for (key, val) in &pairs {
+                    writer.edge(value, key, Some("key"));
+                    walk(interp, writer, key);
+                    writer.edge(value, val, Some("value"));
+                    walk(interp, writer, val);
+                }
+            }
+        }
+        _ => {}
+    }
+    if let Ok(ivars) = value.funcall::<Vec<String>>("instance_variables", &[], None) {
+        for ivar in &ivars {
+            let name = interp.convert(ivar.as_str());
+            if let Ok(ivar_value) = value.funcall::<Value>("instance_variable_get", &[name], None)
+            {
+                writer.edge(value, &ivar_value, Some(ivar));
+                walk(interp, writer, &ivar_value);
+            }
+        }
+    }
+}
+
+impl Artichoke {
+    /// Serialize the object graph reachable from the GC arena to a GraphViz
+    /// DOT document of the given [`Kind`].
+    ///
+    /// Nodes are labeled with [`Value::to_s_debug`] and colored by
+    /// [`Value::ruby_type`]; edges are drawn for array elements, hash
+    /// key/value pairs, and instance variables discovered via
+    /// `instance_variables`. This is a debugging aid for inspecting what
+    /// the mruby GC arena considers live, e.g. after an
+    /// [`ArenaIndex::restore`](crate::gc::ArenaIndex::restore) followed by
+    /// [`full_gc`](crate::gc::MrbGarbageCollection::full_gc).
+    pub fn dump_object_graph_dot(&self, kind: Kind) -> String {
+        let mrb = self.0.borrow().mrb;
+        let mut writer = DotWriter::new(kind);
+        let roots = unsafe {
+            let arena = (*mrb).arena;
+            let arena_idx = (*mrb).arena_idx as usize;
+            (0..arena_idx)
+                .map(|i| {
+                    let basic = *arena.add(i);
+                    Value::new(self, sys::mrb_sys_obj_value(basic as *mut c_void))
+                })
+                .collect::<Vec<_>>()
+        };
+        for root in &roots {
+            walk(self, &mut writer, root);
+        }
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DotWriter, Kind};
+    use crate::eval::Eval;
+    use crate::gc::MrbGarbageCollection;
+
+    #[test]
+    fn dump_digraph_uses_digraph_keyword_and_arrow_edges() {
+        let interp = crate::interpreter().expect("init");
+        let _ = interp.eval("$root = [1, 2]").expect("eval");
+        let dot = interp.dump_object_graph_dot(Kind::Digraph);
+        assert!(dot.starts_with("digraph object_graph {"));
+        assert!(dot.contains("->"));
+        assert!(!dot.contains("--"));
+    }
+
+    #[test]
+    fn dump_graph_uses_graph_keyword_and_undirected_edges() {
+        let interp = crate::interpreter().expect("init");
+        let _ = interp.eval("$root = [1, 2]").expect("eval");
+        let dot = interp.dump_object_graph_dot(Kind::Graph);
+        assert!(dot.starts_with("graph object_graph {"));
+        assert!(dot.contains("--"));
+    }
+
+    #[test]
+    fn walk_draws_array_hash_and_ivar_edges() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp
+            .eval(
+                r#"
+                class Node
+                  def initialize(h)
+                    @h = h
+                  end
+                end
+                Node.new({"a" => [1, 2]})
+                "#,
+            )
+            .expect("eval");
+        let mut writer = DotWriter::new(Kind::Digraph);
+        super::walk(&interp, &mut writer, &value);
+        let dot = writer.finish();
+        assert!(dot.contains("@h"));
+        assert!(dot.contains("key"));
+        assert!(dot.contains("value"));
+    }
+
+    #[test]
+    fn node_label_for_dead_value_does_not_inspect_it() {
+        let interp = crate::interpreter().expect("init");
+        let arena = interp.create_arena_savepoint();
+        let live = interp.eval("'dead'").expect("value");
+        let dead = live;
+        let _live = interp.eval("'live'").expect("value");
+        arena.restore();
+        interp.full_gc();
+        assert!(dead.is_dead());
+
+        let mut writer = DotWriter::new(Kind::Digraph);
+        writer.node(&dead);
+        let dot = writer.finish();
+        // The placeholder label never calls `inspect`, so the string
+        // contents ("dead") never appear quoted (and escaped) as an
+        // inspected value.
+        assert!(dot.contains("<dead"));
+        assert!(!dot.contains("\\\"dead\\\""));
+    }
+}
@@ -0,0 +1,53 @@
+//! Errors returned by fallible operations on an [`Artichoke`](crate::Artichoke)
+//! interpreter.
+
+use std::error;
+use std::fmt;
+
+use crate::exception::Exception;
+
+/// Errors returned by fallible operations in this crate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArtichokeError {
+    /// A Ruby exception was raised and extracted into a structured
+    /// [`Exception`], preserving its class, message, and backtrace.
+    Exception(Exception),
+    /// A Ruby exception was raised, flattened to its `"ClassName: message"`
+    /// string.
+    Exec(String),
+    /// A value could not be converted or coerced to the requested type,
+    /// independent of the mruby VM -- e.g. a [`Conversion`](crate::convert::conversion::Conversion)
+    /// parse failure. Distinct from [`Exception`](Self::Exception) and
+    /// [`Exec`](Self::Exec), which both represent a Ruby exception raised
+    /// by the VM.
+    Conversion(String),
+    /// A [`funcall`](crate::value::ValueLike::funcall) was attempted with
+    /// more arguments than `MRB_FUNCALL_ARGC_MAX`.
+    TooManyArgs {
+        /// The number of arguments given.
+        given: usize,
+        /// The maximum number of arguments accepted.
+        max: usize,
+    },
+    /// A value extracted from the mruby VM had an internal, unspecified
+    /// type tag.
+    ///
+    /// See [`Value::is_unreachable`](crate::value::Value::is_unreachable).
+    UnreachableValue,
+}
+
+impl fmt::Display for ArtichokeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Exception(exception) => write!(f, "{}", exception),
+            Self::Exec(message) => write!(f, "{}", message),
+            Self::Conversion(message) => write!(f, "{}", message),
+            Self::TooManyArgs { given, max } => {
+                write!(f, "Too many args supplied: given {}, max {}", given, max)
+            }
+            Self::UnreachableValue => write!(f, "Value is unreachable"),
+        }
+    }
+}
+
+impl error::Error for ArtichokeError {}
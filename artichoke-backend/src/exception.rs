@@ -0,0 +1,85 @@
+//! Extract the Ruby exception raised by the last eval or funcall off the
+//! mruby VM.
+
+use std::ffi::c_void;
+use std::fmt;
+use std::ptr;
+
+use crate::sys;
+use crate::value::{Value, ValueLike};
+use crate::{Artichoke, ArtichokeError};
+
+/// A structured representation of a raised Ruby exception.
+///
+/// Preserves the exception's class name, message, and backtrace so
+/// embedders can implement Ruby-aware error handling -- rescue-by-class,
+/// structured logging -- instead of matching on a flattened error string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Exception {
+    /// The name of the exception's class, e.g. `"TypeError"`.
+    pub class: String,
+    /// The exception's `message`.
+    pub message: String,
+    /// The exception's backtrace, one frame per entry, innermost (most
+    /// recently executed, i.e. where the exception was raised) frame
+    /// first, oldest call last.
+    pub backtrace: Vec<String>,
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.class, self.message)
+    }
+}
+
+/// The outcome of checking the mruby VM for an exception raised by the most
+/// recent eval or funcall.
+pub enum LastError {
+    /// No exception was raised.
+    None,
+    /// An exception was raised and successfully extracted.
+    Some(Exception),
+    /// An exception was raised but could not be extracted into an
+    /// [`Exception`].
+    UnableToExtract(ArtichokeError),
+}
+
+/// Check an [`Artichoke`] interpreter for an exception raised by the most
+/// recent eval or funcall.
+pub trait ExceptionHandler {
+    /// Extract the [`LastError`] from this interpreter, clearing the
+    /// exception from the underlying mruby VM if one is set.
+    fn last_error(&self) -> LastError;
+}
+
+impl ExceptionHandler for Artichoke {
+    fn last_error(&self) -> LastError {
+        let mrb = self.0.borrow().mrb;
+        let exc = unsafe { (*mrb).exc };
+        if exc.is_null() {
+            return LastError::None;
+        }
+        unsafe {
+            (*mrb).exc = ptr::null_mut();
+        }
+        let exception = unsafe {
+            let value = sys::mrb_sys_obj_value(exc as *mut c_void);
+            Value::new(self, value)
+        };
+        let class = exception
+            .funcall::<Value>("class", &[], None)
+            .and_then(|class| class.funcall::<String>("name", &[], None));
+        let message = exception.funcall::<String>("message", &[], None);
+        let backtrace = exception
+            .funcall::<Option<Vec<String>>>("backtrace", &[], None)
+            .map(|backtrace| backtrace.unwrap_or_default());
+        match (class, message, backtrace) {
+            (Ok(class), Ok(message), Ok(backtrace)) => LastError::Some(Exception {
+                class,
+                message,
+                backtrace,
+            }),
+            (Err(err), ..) | (_, Err(err), _) | (.., Err(err)) => LastError::UnableToExtract(err),
+        }
+    }
+}
@@ -101,7 +101,54 @@ pub fn method(interp: &Artichoke, args: Args, value: &Value) -> Result<Value, Er
                 interp.convert(None::<Value>)
             }
         }
-        Backend::Rust(_) => unimplemented!("Rust-backed Regexp"),
+        Backend::Rust(regex) => {
+            if let Some(globals) = rust_backend_match_globals(regex, string.as_str()) {
+                let num_regexp_globals_to_set = {
+                    let num_previously_set_globals =
+                        interp.0.borrow().num_set_regexp_capture_globals;
+                    cmp::max(num_previously_set_globals, globals.groups.len())
+                };
+                for group in 0..num_regexp_globals_to_set {
+                    let sym = if group == 0 {
+                        interp.0.borrow_mut().sym_intern("$&")
+                    } else {
+                        interp.0.borrow_mut().sym_intern(&format!("${}", group))
+                    };
+
+                    let value =
+                        interp.convert(globals.groups.get(group).and_then(Option::as_deref));
+                    unsafe {
+                        sys::mrb_gv_set(mrb, sym, value.inner());
+                    }
+                }
+                interp.0.borrow_mut().num_set_regexp_capture_globals = globals.groups.len();
+
+                unsafe {
+                    let pre_match_sym = interp.0.borrow_mut().sym_intern("$`");
+                    sys::mrb_gv_set(
+                        mrb,
+                        pre_match_sym,
+                        interp.convert(globals.pre_match.as_str()).inner(),
+                    );
+                    let post_match_sym = interp.0.borrow_mut().sym_intern("$'");
+                    sys::mrb_gv_set(
+                        mrb,
+                        post_match_sym,
+                        interp.convert(globals.post_match.as_str()).inner(),
+                    );
+                }
+                let matchdata = MatchData::new(string.as_str(), borrow.clone(), 0, string.len());
+                unsafe { matchdata.try_into_ruby(&interp, None) }.map_err(|_| Error::Fatal)?
+            } else {
+                unsafe {
+                    let pre_match_sym = interp.0.borrow_mut().sym_intern("$`");
+                    sys::mrb_gv_set(mrb, pre_match_sym, interp.convert(None::<Value>).inner());
+                    let post_match_sym = interp.0.borrow_mut().sym_intern("$'");
+                    sys::mrb_gv_set(mrb, post_match_sym, interp.convert(None::<Value>).inner());
+                }
+                interp.convert(None::<Value>)
+            }
+        }
     };
     unsafe {
         sys::mrb_gv_set(
@@ -112,3 +159,76 @@ pub fn method(interp: &Artichoke, args: Args, value: &Value) -> Result<Value, Er
     }
     Ok(interp.convert(!unsafe { sys::mrb_sys_value_is_nil(matchdata.inner()) }))
 }
+
+/// The `$~`-family global values derived from matching a `Backend::Rust`
+/// regex against `string`.
+///
+/// Pulled out of the `Backend::Rust` arm of [`method`] so the capture and
+/// pre/post-match bookkeeping -- the part of this module that makes the
+/// Rust and Onig backends "behaviorally interchangeable" -- can be unit
+/// tested without an mruby VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MatchGlobals {
+    /// `groups[0]` is `$&`; `groups[n]` for `n >= 1` is `$n`.
+    groups: Vec<Option<String>>,
+    /// `` $` ``: the substring before the group-0 match.
+    pre_match: String,
+    /// `$'`: the substring after the group-0 match.
+    post_match: String,
+}
+
+fn rust_backend_match_globals(regex: &regex::Regex, string: &str) -> Option<MatchGlobals> {
+    let captures = regex.captures(string)?;
+    let groups = (0..captures.len())
+        .map(|group| captures.get(group).map(|group| group.as_str().to_owned()))
+        .collect();
+    let match_pos = captures.get(0)?;
+    Some(MatchGlobals {
+        groups,
+        pre_match: string[..match_pos.start()].to_owned(),
+        post_match: string[match_pos.end()..].to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rust_backend_match_globals, MatchGlobals};
+
+    #[test]
+    fn rust_backend_sets_captures_and_pre_post_match_on_match() {
+        let regex = regex::Regex::new(r"(\d+)-(\d+)").unwrap();
+        let globals = rust_backend_match_globals(&regex, "id 12-34 end").unwrap();
+        assert_eq!(
+            globals,
+            MatchGlobals {
+                groups: vec![
+                    Some("12-34".to_owned()),
+                    Some("12".to_owned()),
+                    Some("34".to_owned()),
+                ],
+                pre_match: "id ".to_owned(),
+                post_match: " end".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn rust_backend_returns_none_on_no_match() {
+        let regex = regex::Regex::new(r"\d+").unwrap();
+        assert_eq!(rust_backend_match_globals(&regex, "no digits here"), None);
+    }
+
+    #[test]
+    fn rust_backend_unset_optional_group_is_none() {
+        let regex = regex::Regex::new(r"(\d+)|([a-z]+)").unwrap();
+        let globals = rust_backend_match_globals(&regex, "abc").unwrap();
+        assert_eq!(
+            globals,
+            MatchGlobals {
+                groups: vec![Some("abc".to_owned()), None, Some("abc".to_owned())],
+                pre_match: String::new(),
+                post_match: String::new(),
+            }
+        );
+    }
+}
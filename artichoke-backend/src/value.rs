@@ -3,6 +3,7 @@ use std::ffi::c_void;
 use std::fmt;
 use std::mem;
 
+use crate::convert::conversion::{self, Conversion};
 use crate::convert::{Convert, TryConvert};
 use crate::exception::{ExceptionHandler, LastError};
 use crate::gc::MrbGarbageCollection;
@@ -142,6 +143,20 @@ impl Value {
     pub fn to_s_debug(&self) -> String {
         format!("{}<{}>", self.ruby_type().class_name(), self.inspect())
     }
+
+    /// Coerce a `String` value into the Ruby type named by `conv`.
+    ///
+    /// This allows embedders to turn loosely-typed, string-shaped Ruby
+    /// values into typed ones declaratively, without hand-writing
+    /// `funcall("to_i")` chains.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is not a `String`, or if the string cannot be parsed as
+    /// the type named by `conv`, an [`ArtichokeError`] is returned.
+    pub fn coerce(&self, conv: &Conversion) -> Result<Self, ArtichokeError> {
+        conversion::coerce(&self.interp, self, conv)
+    }
 }
 
 impl ValueLike for Value {
@@ -211,7 +226,7 @@ impl ValueLike for Value {
         match self.interp.last_error() {
             LastError::Some(exception) => {
                 warn!("runtime error with exception backtrace: {}", exception);
-                Err(ArtichokeError::Exec(exception.to_string()))
+                Err(ArtichokeError::Exception(exception))
             }
             LastError::UnableToExtract(err) => {
                 error!("failed to extract exception after runtime error: {}", err);
@@ -305,6 +320,7 @@ impl Clone for Value {
 mod tests {
     use crate::convert::Convert;
     use crate::eval::Eval;
+    use crate::exception::Exception;
     use crate::gc::MrbGarbageCollection;
     use crate::value::{Value, ValueLike};
     use crate::ArtichokeError;
@@ -538,12 +554,13 @@ mod tests {
         let nil = interp.convert(None::<Value>);
         let s = interp.convert("foo");
         let result = s.funcall::<String>("+", &[nil], None);
-        assert_eq!(
-            result,
-            Err(ArtichokeError::Exec(
-                "TypeError: nil cannot be converted to String".to_owned()
-            ))
-        );
+        match result {
+            Err(ArtichokeError::Exception(exception)) => {
+                assert_eq!(exception.class, "TypeError");
+                assert_eq!(exception.message, "nil cannot be converted to String");
+            }
+            other => panic!("expected TypeError exception, got {:?}", other),
+        }
     }
 
     #[test]
@@ -552,11 +569,12 @@ mod tests {
         let nil = interp.convert(None::<Value>);
         let s = interp.convert("foo");
         let result = nil.funcall::<bool>("garbage_method_name", &[s], None);
-        assert_eq!(
-            result,
-            Err(ArtichokeError::Exec(
-                "NoMethodError: undefined method 'garbage_method_name'".to_owned()
-            ))
-        );
+        match result {
+            Err(ArtichokeError::Exception(exception)) => {
+                assert_eq!(exception.class, "NoMethodError");
+                assert_eq!(exception.message, "undefined method 'garbage_method_name'");
+            }
+            other => panic!("expected NoMethodError exception, got {:?}", other),
+        }
     }
 }